@@ -0,0 +1,147 @@
+use crate::{config, weather};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 900;
+
+/// Shared, in-memory state for the background weather-alert subsystem.
+pub struct AlertState {
+    pub enabled: AtomicBool,
+    pub poll_interval_secs: AtomicU64,
+    seen_alert_ids: Mutex<HashSet<String>>,
+}
+
+impl Default for AlertState {
+    fn default() -> Self {
+        Self {
+            enabled: AtomicBool::new(true),
+            poll_interval_secs: AtomicU64::new(DEFAULT_POLL_INTERVAL_SECS),
+            seen_alert_ids: Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+/// A single severe-weather condition worth surfacing to the user.
+struct Alert {
+    id: String,
+    title: &'static str,
+    body: String,
+}
+
+/// Starts the background task that periodically polls the weather provider and
+/// fires an OS notification whenever a severe-weather threshold is crossed.
+/// Thresholds and the poll interval are read from `AlertState`/persisted config
+/// on every tick, so the `set_alert_settings` command takes effect immediately.
+pub fn start(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let interval = {
+                let state = app.state::<AlertState>();
+                state.poll_interval_secs.load(Ordering::Relaxed)
+            };
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+
+            let enabled = app.state::<AlertState>().enabled.load(Ordering::Relaxed);
+            if enabled {
+                poll_now(&app).await;
+            }
+        }
+    });
+}
+
+/// Polls the weather provider once, fires notifications for any newly-crossed
+/// thresholds, and refreshes the tray icon. Called on the timer in [`start`]
+/// and on-demand from the tray's "Refresh" menu item.
+pub(crate) async fn poll_now(app: &AppHandle) {
+    let cfg = config::load(app);
+    let Some(location) = cfg.last_location.clone() else {
+        return;
+    };
+
+    let cache = app.state::<weather::WeatherCache>();
+    let Ok(snapshot) = weather::fetch_snapshot(app, &cache, &location).await else {
+        return;
+    };
+
+    #[cfg(not(mobile))]
+    crate::tray::update(app, &snapshot.current);
+
+    let crossed = crossed_thresholds(&cfg, &snapshot);
+
+    // Drop ids whose condition no longer holds so a recurring event (e.g. a
+    // cold snap next month after today's) can notify again instead of being
+    // suppressed for the lifetime of the process.
+    let still_active: HashSet<&str> = crossed.iter().map(|alert| alert.id.as_str()).collect();
+    app.state::<AlertState>()
+        .seen_alert_ids
+        .lock()
+        .unwrap()
+        .retain(|id| still_active.contains(id.as_str()));
+
+    for alert in crossed {
+        notify_once(app, alert);
+    }
+}
+
+fn crossed_thresholds(cfg: &config::AppConfig, snapshot: &weather::WeatherSnapshot) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+
+    if let Some(low) = cfg.low_temp_c {
+        if snapshot.current.temperature_c < low {
+            alerts.push(Alert {
+                id: format!("low-temp:{}", snapshot.location),
+                title: "Cold weather alert",
+                body: format!(
+                    "{} has dropped to {:.0}°C",
+                    snapshot.location, snapshot.current.temperature_c
+                ),
+            });
+        }
+    }
+
+    if let Some(high) = cfg.high_temp_c {
+        if snapshot.current.temperature_c > high {
+            alerts.push(Alert {
+                id: format!("high-temp:{}", snapshot.location),
+                title: "Heat warning",
+                body: format!(
+                    "{} has risen to {:.0}°C",
+                    snapshot.location, snapshot.current.temperature_c
+                ),
+            });
+        }
+    }
+
+    if cfg.notify_on_precipitation && snapshot.current.condition.to_lowercase().contains("rain") {
+        alerts.push(Alert {
+            id: format!("precipitation:{}", snapshot.location),
+            title: "Rain starting soon",
+            body: format!("Precipitation expected in {} within the hour", snapshot.location),
+        });
+    }
+
+    alerts
+}
+
+/// Fires the OS notification unless this exact alert id already fired, so the
+/// same advisory doesn't repeat on every poll tick.
+fn notify_once(app: &AppHandle, alert: Alert) {
+    let is_new = {
+        let state = app.state::<AlertState>();
+        state.seen_alert_ids.lock().unwrap().insert(alert.id)
+    };
+    if !is_new {
+        return;
+    }
+
+    let _ = app
+        .notification()
+        .builder()
+        .title(alert.title)
+        .body(alert.body)
+        .show();
+}
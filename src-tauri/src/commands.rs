@@ -0,0 +1,68 @@
+use crate::alerts::AlertState;
+use crate::config;
+use crate::weather::{self, CurrentConditions, ForecastDay, GeocodeResult, WeatherCache};
+use std::sync::atomic::Ordering;
+use tauri::{AppHandle, State};
+
+/// Enables or disables background weather-alert polling, sets how often it
+/// checks the provider, and persists the severe-weather thresholds it polls
+/// against so they survive a restart.
+#[tauri::command]
+pub fn set_alert_settings(
+    app: AppHandle,
+    enabled: bool,
+    poll_interval_secs: u64,
+    low_temp_c: Option<f64>,
+    high_temp_c: Option<f64>,
+    notify_on_precipitation: bool,
+    state: State<AlertState>,
+) -> Result<(), String> {
+    state.enabled.store(enabled, Ordering::Relaxed);
+    state
+        .poll_interval_secs
+        .store(poll_interval_secs.max(60), Ordering::Relaxed);
+
+    config::update(&app, |cfg| {
+        cfg.low_temp_c = low_temp_c;
+        cfg.high_temp_c = high_temp_c;
+        cfg.notify_on_precipitation = notify_on_precipitation;
+    })
+    .map_err(|err| err.to_string())
+}
+
+/// Returns current conditions for `(lat, lon)`, backed by the shared TTL cache.
+#[tauri::command]
+pub async fn get_current_weather(
+    app: AppHandle,
+    cache: State<'_, WeatherCache>,
+    lat: f64,
+    lon: f64,
+) -> Result<CurrentConditions, String> {
+    weather::get_current_weather(&app, &cache, lat, lon).await
+}
+
+/// Returns the `days`-day forecast for `(lat, lon)`, backed by the shared TTL cache.
+#[tauri::command]
+pub async fn get_forecast(
+    app: AppHandle,
+    cache: State<'_, WeatherCache>,
+    lat: f64,
+    lon: f64,
+    days: u8,
+) -> Result<Vec<ForecastDay>, String> {
+    weather::get_forecast(&app, &cache, lat, lon, days).await
+}
+
+/// Resolves a free-text place name to candidate coordinates, and remembers the
+/// top match as the last-saved location so the splashscreen prefetch and the
+/// background alert subsystem have somewhere to look on the next launch.
+#[tauri::command]
+pub async fn geocode(app: AppHandle, query: String) -> Result<Vec<GeocodeResult>, String> {
+    let matches = weather::geocode(&query).await?;
+
+    if let Some(top) = matches.first() {
+        let _ = config::update(&app, |cfg| cfg.last_location = Some(top.name.clone()));
+    }
+
+    Ok(matches)
+}
@@ -0,0 +1,76 @@
+use crate::weather::GeocodeResult;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// Small persisted bits of app state that survive restarts, such as the last
+/// location the user looked up, their severe-weather alert thresholds, and
+/// tray/shortcut preferences.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    pub last_location: Option<String>,
+    /// The last successful geocode for `last_location`, kept so a fetch can
+    /// still resolve coordinates when the provider is unreachable.
+    pub last_place: Option<GeocodeResult>,
+    pub low_temp_c: Option<f64>,
+    pub high_temp_c: Option<f64>,
+    #[serde(default)]
+    pub notify_on_precipitation: bool,
+    #[serde(default = "default_tray_enabled")]
+    pub tray_enabled: bool,
+    #[serde(default = "default_toggle_shortcut")]
+    pub toggle_shortcut: String,
+}
+
+fn default_tray_enabled() -> bool {
+    true
+}
+
+fn default_toggle_shortcut() -> String {
+    "Ctrl+Shift+W".to_string()
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            last_location: None,
+            last_place: None,
+            low_temp_c: None,
+            high_temp_c: None,
+            notify_on_precipitation: false,
+            tray_enabled: default_tray_enabled(),
+            toggle_shortcut: default_toggle_shortcut(),
+        }
+    }
+}
+
+fn config_path(app: &AppHandle) -> PathBuf {
+    app.path()
+        .app_config_dir()
+        .expect("app config dir")
+        .join("config.json")
+}
+
+/// Loads the persisted config, falling back to defaults if none exists yet.
+pub fn load(app: &AppHandle) -> AppConfig {
+    std::fs::read_to_string(config_path(app))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Persists `config` to disk, creating the app config directory if needed.
+pub fn save(app: &AppHandle, config: &AppConfig) -> std::io::Result<()> {
+    let path = config_path(app);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(config)?)
+}
+
+/// Loads the persisted config, applies `mutate`, and saves the result back.
+pub fn update(app: &AppHandle, mutate: impl FnOnce(&mut AppConfig)) -> std::io::Result<()> {
+    let mut config = load(app);
+    mutate(&mut config);
+    save(app, &config)
+}
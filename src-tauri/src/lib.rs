@@ -1,17 +1,190 @@
+use tauri::Manager;
+
+mod alerts;
+mod commands;
+mod config;
+#[cfg(not(mobile))]
+mod tray;
+mod weather;
+
 /// Runs the Tauri application.
 /// Initializes the Tauri builder, registers plugins, and starts the application loop.
 ///
 /// This function is also used as the mobile entry point.
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    let mut builder = tauri::Builder::default().plugin(tauri_plugin_shell::init());
+    let mut builder = tauri::Builder::default()
+        .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_notification::init())
+        .manage(alerts::AlertState::default())
+        .manage(weather::WeatherCache::default())
+        .invoke_handler(tauri::generate_handler![
+            commands::set_alert_settings,
+            commands::get_current_weather,
+            commands::get_forecast,
+            commands::geocode,
+        ]);
 
     #[cfg(not(mobile))]
     {
-        builder = builder.plugin(tauri_plugin_window_state::Builder::default().build());
+        builder = builder
+            .plugin(tauri_plugin_window_state::Builder::default().build())
+            .plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
+                focus_main_window(app);
+
+                if let Some(location) = location_arg(&args) {
+                    emit_focus_location(app, location);
+                }
+            }))
+            .plugin(
+                tauri_plugin_global_shortcut::Builder::new()
+                    .with_handler(|app, _shortcut, event| {
+                        if event.state() == tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                            tray::toggle_popover(app);
+                        }
+                    })
+                    .build(),
+            );
+    }
+
+    #[cfg(mobile)]
+    {
+        builder = builder
+            .plugin(tauri_plugin_geolocation::init())
+            .plugin(tauri_plugin_deep_link::init());
     }
 
     builder
+        .setup(|app| {
+            let app_handle = app.handle().clone();
+
+            if let Some(splash) = app.get_webview_window("splashscreen") {
+                let _ = splash.show();
+            }
+
+            alerts::start(app_handle.clone());
+
+            #[cfg(not(mobile))]
+            if config::load(&app_handle).tray_enabled {
+                tray::build(&app_handle)?;
+                tray::register_shortcut(&app_handle)?;
+            }
+
+            #[cfg(mobile)]
+            register_deep_link(&app_handle)?;
+
+            tauri::async_runtime::spawn(async move {
+                prefetch_weather(&app_handle).await;
+            });
+
+            Ok(())
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+/// Fetches the last-saved location's weather in the background, then swaps the
+/// splashscreen for the main window and hands the frontend a ready-made payload
+/// via `weather-ready` so it doesn't have to issue its own first request.
+async fn prefetch_weather(app: &tauri::AppHandle) {
+    let location = config::load(app)
+        .last_location
+        .unwrap_or_else(|| "New York".to_string());
+
+    let cache = app.state::<weather::WeatherCache>();
+    let snapshot = weather::fetch_snapshot(app, &cache, &location).await;
+
+    if let Some(splash) = app.get_webview_window("splashscreen") {
+        let _ = splash.close();
+    }
+    if let Some(main) = app.get_webview_window("main") {
+        let _ = main.show();
+    }
+
+    if let Ok(snapshot) = snapshot {
+        #[cfg(not(mobile))]
+        tray::update(app, &snapshot.current);
+
+        let _ = app.emit("weather-ready", snapshot);
+    }
+}
+
+/// Brings the existing main window to the front when a second instance is launched.
+#[cfg(not(mobile))]
+fn focus_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+}
+
+/// Extracts the city passed via `--location "City"` on a relaunch, if present.
+#[cfg(not(mobile))]
+fn location_arg(args: &[String]) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == "--location")
+        .and_then(|idx| args.get(idx + 1))
+        .cloned()
+}
+
+/// Geocodes `location` and emits it as a `focus-location` event, using the
+/// same `{ lat, lon }` payload shape the mobile deep-link handler emits, so
+/// the frontend only needs one listener for either platform.
+#[cfg(not(mobile))]
+fn emit_focus_location(app: &tauri::AppHandle, location: String) {
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Ok(results) = weather::geocode(&location).await {
+            if let Some(place) = results.into_iter().next() {
+                let _ = app_handle.emit(
+                    "focus-location",
+                    weather::FocusLocation {
+                        lat: place.lat,
+                        lon: place.lon,
+                    },
+                );
+            }
+        }
+    });
+}
+
+/// Listens for `weather://location?lat=..&lon=..` deep links and forwards the
+/// coordinates to the frontend as a `focus-location` event, mirroring the
+/// desktop `--location` relaunch flag.
+#[cfg(mobile)]
+fn register_deep_link(app: &tauri::AppHandle) -> tauri::Result<()> {
+    use tauri_plugin_deep_link::DeepLinkExt;
+
+    let app_handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            if let Some(location) = parse_location_url(&url) {
+                let _ = app_handle.emit("focus-location", location);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(mobile)]
+fn parse_location_url(url: &url::Url) -> Option<weather::FocusLocation> {
+    if url.scheme() != "weather" || url.host_str() != Some("location") {
+        return None;
+    }
+
+    let mut lat = None;
+    let mut lon = None;
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "lat" => lat = value.parse().ok(),
+            "lon" => lon = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    Some(weather::FocusLocation {
+        lat: lat?,
+        lon: lon?,
+    })
+}
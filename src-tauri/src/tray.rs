@@ -0,0 +1,126 @@
+use crate::{alerts, config, weather};
+use tauri::menu::{Menu, MenuItem};
+use tauri::path::BaseDirectory;
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager, WindowEvent};
+use tauri_plugin_global_shortcut::GlobalShortcutExt;
+
+const TRAY_ID: &str = "main";
+const POPOVER_LABEL: &str = "popover";
+
+/// Builds the tray icon and its Show/Hide/Refresh/Quit menu, and makes the
+/// main window hide to tray instead of quitting on close when tray mode is
+/// enabled in the persisted config.
+pub fn build(app: &AppHandle) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+    let hide = MenuItem::with_id(app, "hide", "Hide", true, None::<&str>)?;
+    let refresh = MenuItem::with_id(app, "refresh", "Refresh", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show, &hide, &refresh, &quit])?;
+
+    let mut tray_builder = TrayIconBuilder::with_id(TRAY_ID).menu(&menu).tooltip("Weather");
+    if let Some(icon) = app.default_window_icon() {
+        tray_builder = tray_builder.icon(icon.clone());
+    }
+
+    tray_builder
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            "show" => show_main_window(app),
+            "hide" => hide_main_window(app),
+            "refresh" => {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    alerts::poll_now(&app).await;
+                });
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .build(app)?;
+
+    if let Some(main) = app.get_webview_window("main") {
+        let app_handle = app.clone();
+        main.on_window_event(move |event| {
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                if config::load(&app_handle).tray_enabled {
+                    api.prevent_close();
+                    hide_main_window(&app_handle);
+                }
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Registers the global hotkey (default `Ctrl+Shift+W`) that toggles the
+/// always-on-top popover window.
+pub fn register_shortcut(app: &AppHandle) -> tauri::Result<()> {
+    let shortcut = config::load(app).toggle_shortcut;
+    if let Ok(shortcut) = shortcut.parse() {
+        app.global_shortcut().register(shortcut)?;
+    }
+    Ok(())
+}
+
+/// Shows or hides the popover window, used by the global-shortcut handler.
+pub fn toggle_popover(app: &AppHandle) {
+    let Some(popover) = app.get_webview_window(POPOVER_LABEL) else {
+        return;
+    };
+
+    if popover.is_visible().unwrap_or(false) {
+        let _ = popover.hide();
+    } else {
+        let _ = popover.show();
+        let _ = popover.set_focus();
+    }
+}
+
+/// Refreshes the tray tooltip and icon to reflect the latest poll.
+pub fn update(app: &AppHandle, current: &weather::CurrentConditions) {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let _ = tray.set_tooltip(Some(format!(
+            "{:.0}°C, {}",
+            current.temperature_c, current.condition
+        )));
+
+        let resolved_icon = app
+            .path()
+            .resolve(icon_for_condition(&current.condition), BaseDirectory::Resource)
+            .ok()
+            .and_then(|path| tauri::image::Image::from_path(path).ok());
+        if let Some(icon) = resolved_icon {
+            let _ = tray.set_icon(Some(icon));
+        }
+    }
+}
+
+/// Maps a condition string to the bundled tray icon asset that best
+/// represents it, falling back to the generic/sunny icon for anything
+/// unrecognized.
+fn icon_for_condition(condition: &str) -> &'static str {
+    let condition = condition.to_lowercase();
+    if condition.contains("snow") {
+        "icons/tray-snow.png"
+    } else if condition.contains("rain") || condition.contains("storm") {
+        "icons/tray-rain.png"
+    } else if condition.contains("cloud") {
+        "icons/tray-cloudy.png"
+    } else {
+        "icons/tray-sunny.png"
+    }
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(main) = app.get_webview_window("main") {
+        let _ = main.show();
+        let _ = main.set_focus();
+    }
+}
+
+fn hide_main_window(app: &AppHandle) {
+    if let Some(main) = app.get_webview_window("main") {
+        let _ = main.hide();
+    }
+}
@@ -0,0 +1,244 @@
+use crate::config;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+const CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrentConditions {
+    pub temperature_c: f64,
+    pub condition: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForecastDay {
+    pub date: String,
+    pub high_c: f64,
+    pub low_c: f64,
+    pub condition: String,
+}
+
+/// Current conditions plus the multi-day forecast for a single location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherSnapshot {
+    pub location: String,
+    pub current: CurrentConditions,
+    pub forecast: Vec<ForecastDay>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeocodeResult {
+    pub name: String,
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/// Coordinates for a location the frontend should jump to and look up.
+/// Emitted as the `focus-location` event by both the desktop relaunch
+/// `--location` flag and the mobile `weather://location` deep link, so the
+/// frontend only needs one listener shape for either platform.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusLocation {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/// Coordinates rounded to ~1km so nearby requests share a cache entry.
+type CoordKey = (i64, i64);
+
+fn coord_key(lat: f64, lon: f64) -> CoordKey {
+    ((lat * 100.0).round() as i64, (lon * 100.0).round() as i64)
+}
+
+struct Cached<T> {
+    value: T,
+    fetched_at: Instant,
+}
+
+/// In-memory TTL cache for weather responses, keyed by rounded coordinates.
+/// Every successful fetch also lands on disk so offline launches still have
+/// stale-but-usable data to show.
+#[derive(Default)]
+pub struct WeatherCache {
+    current: Mutex<HashMap<CoordKey, Cached<CurrentConditions>>>,
+    forecast: Mutex<HashMap<(CoordKey, u8), Cached<Vec<ForecastDay>>>>,
+}
+
+/// Returns current conditions for `(lat, lon)`, serving from the in-memory
+/// cache when fresh and from the on-disk fallback when the live fetch fails.
+pub async fn get_current_weather(
+    app: &AppHandle,
+    cache: &WeatherCache,
+    lat: f64,
+    lon: f64,
+) -> Result<CurrentConditions, String> {
+    let key = coord_key(lat, lon);
+
+    if let Some(entry) = cache.current.lock().unwrap().get(&key) {
+        if entry.fetched_at.elapsed() < CACHE_TTL {
+            return Ok(entry.value.clone());
+        }
+    }
+
+    match fetch_current(lat, lon).await {
+        Ok(current) => {
+            cache.current.lock().unwrap().insert(
+                key,
+                Cached {
+                    value: current.clone(),
+                    fetched_at: Instant::now(),
+                },
+            );
+            save_to_disk(app, "current", key, &current);
+            Ok(current)
+        }
+        Err(err) => load_from_disk(app, "current", key).ok_or(err),
+    }
+}
+
+/// Returns the `days`-day forecast for `(lat, lon)`, with the same cache and
+/// on-disk fallback rules as [`get_current_weather`].
+pub async fn get_forecast(
+    app: &AppHandle,
+    cache: &WeatherCache,
+    lat: f64,
+    lon: f64,
+    days: u8,
+) -> Result<Vec<ForecastDay>, String> {
+    let key = coord_key(lat, lon);
+
+    if let Some(entry) = cache.forecast.lock().unwrap().get(&(key, days)) {
+        if entry.fetched_at.elapsed() < CACHE_TTL {
+            return Ok(entry.value.clone());
+        }
+    }
+
+    match fetch_forecast(lat, lon, days).await {
+        Ok(forecast) => {
+            cache.forecast.lock().unwrap().insert(
+                (key, days),
+                Cached {
+                    value: forecast.clone(),
+                    fetched_at: Instant::now(),
+                },
+            );
+            save_to_disk(app, "forecast", key, &forecast);
+            Ok(forecast)
+        }
+        Err(err) => load_from_disk(app, "forecast", key).ok_or(err),
+    }
+}
+
+/// The provider API key, kept server-side so it never reaches the webview.
+fn api_key() -> Result<String, String> {
+    std::env::var("WEATHER_API_KEY")
+        .map_err(|_| "WEATHER_API_KEY is not set".to_string())
+}
+
+/// Resolves a free-text place name to one or more candidate coordinates.
+pub async fn geocode(query: &str) -> Result<Vec<GeocodeResult>, String> {
+    reqwest::Client::new()
+        .get("https://api.weatherprovider.com/v1/geocode")
+        .query(&[("q", query), ("key", &api_key()?)])
+        .send()
+        .await
+        .map_err(|err| err.to_string())?
+        .json::<Vec<GeocodeResult>>()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Looks up a location by name, then returns its current conditions and
+/// forecast in one call. This is the path the splashscreen prefetch and the
+/// background alert subsystem use, since both only have a location name on
+/// hand rather than coordinates.
+///
+/// The geocode step itself has no HTTP-level cache, so on an offline cold
+/// start it falls back to the last successful geocode for this location
+/// (persisted in config) rather than failing before ever reaching the
+/// current-conditions/forecast disk fallback.
+pub async fn fetch_snapshot(
+    app: &AppHandle,
+    cache: &WeatherCache,
+    location: &str,
+) -> Result<WeatherSnapshot, String> {
+    let place = match geocode(location).await {
+        Ok(mut matches) if !matches.is_empty() => {
+            let place = matches.remove(0);
+            let _ = config::update(app, |cfg| cfg.last_place = Some(place.clone()));
+            place
+        }
+        _ => config::load(app)
+            .last_place
+            .filter(|cached| cached.name.eq_ignore_ascii_case(location))
+            .ok_or_else(|| format!("no match for \"{location}\""))?,
+    };
+
+    let current = get_current_weather(app, cache, place.lat, place.lon).await?;
+    let forecast = get_forecast(app, cache, place.lat, place.lon, 5).await?;
+
+    Ok(WeatherSnapshot {
+        location: place.name,
+        current,
+        forecast,
+    })
+}
+
+async fn fetch_current(lat: f64, lon: f64) -> Result<CurrentConditions, String> {
+    reqwest::Client::new()
+        .get("https://api.weatherprovider.com/v1/current")
+        .query(&[("lat", lat.to_string()), ("lon", lon.to_string()), ("key", api_key()?)])
+        .send()
+        .await
+        .map_err(|err| err.to_string())?
+        .json::<CurrentConditions>()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+async fn fetch_forecast(lat: f64, lon: f64, days: u8) -> Result<Vec<ForecastDay>, String> {
+    reqwest::Client::new()
+        .get("https://api.weatherprovider.com/v1/forecast")
+        .query(&[
+            ("lat", lat.to_string()),
+            ("lon", lon.to_string()),
+            ("days", days.to_string()),
+            ("key", api_key()?),
+        ])
+        .send()
+        .await
+        .map_err(|err| err.to_string())?
+        .json::<Vec<ForecastDay>>()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Returns `None` rather than panicking when the app cache dir can't be
+/// resolved, so a disk-cache miss just means falling back to the live
+/// network result instead of crashing the calling command.
+fn disk_cache_path(app: &AppHandle, kind: &str, key: CoordKey) -> Option<std::path::PathBuf> {
+    app.path()
+        .app_cache_dir()
+        .ok()
+        .map(|dir| dir.join(format!("weather_{kind}_{}_{}.json", key.0, key.1)))
+}
+
+fn save_to_disk<T: Serialize>(app: &AppHandle, kind: &str, key: CoordKey, value: &T) {
+    let Some(path) = disk_cache_path(app, kind, key) else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string(value) {
+        let _ = std::fs::write(path, json);
+    }
+}
+
+fn load_from_disk<T: for<'de> Deserialize<'de>>(app: &AppHandle, kind: &str, key: CoordKey) -> Option<T> {
+    let contents = std::fs::read_to_string(disk_cache_path(app, kind, key)?).ok()?;
+    serde_json::from_str(&contents).ok()
+}